@@ -1,10 +1,76 @@
-use std::path::Path;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::fs;
+use std::fs::File;
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use jsonschema::{Draft, JSONSchema};
+use once_cell::sync::Lazy;
+use serde::Serialize;
 use serde_json::{Value as JsonValue};
+use tauri_plugin_http::reqwest;
 
-#[tauri::command]
-async fn write_json_file(filename: String, data: JsonValue) -> Result<(), String> {
-    let app_dir = match std::env::var("APPDATA") {
+const DEFAULT_CONNECT_TIMEOUT_SECS: u64 = 10;
+const DEFAULT_READ_TIMEOUT_SECS: u64 = 30;
+const DEFAULT_CACHE_BYTE_BUDGET: u64 = 200 * 1024 * 1024;
+
+// Compiling a JSON Schema is far more expensive than validating against an
+// already-compiled one, so compiled schemas are cached here keyed by their
+// own serialized text. Callers just pass the schema value each time; repeat
+// calls with the same schema reuse the compiled validator.
+static SCHEMA_CACHE: Lazy<Mutex<HashMap<String, Arc<JSONSchema>>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+#[derive(Serialize)]
+struct SchemaViolation {
+    path: String,
+    keyword: String,
+    message: String,
+}
+
+fn compiled_schema(schema: &JsonValue) -> Result<Arc<JSONSchema>, String> {
+    let cache_key = schema.to_string();
+
+    if let Some(compiled) = SCHEMA_CACHE.lock().unwrap().get(&cache_key) {
+        return Ok(compiled.clone());
+    }
+
+    let compiled = Arc::new(
+        JSONSchema::options()
+            .with_draft(Draft::Draft7)
+            .compile(schema)
+            .map_err(|e| format!("Invalid JSON Schema: {}", e))?,
+    );
+
+    SCHEMA_CACHE.lock().unwrap().insert(cache_key, compiled.clone());
+    Ok(compiled)
+}
+
+// Validates `data` against `schema`, returning a JSON-encoded list of
+// violations (pointer path + violated keyword + message) on failure so the
+// frontend can show field-level errors instead of a generic parse failure.
+fn validate_against_schema(data: &JsonValue, schema: &JsonValue) -> Result<(), String> {
+    let compiled = compiled_schema(schema)?;
+
+    if let Err(errors) = compiled.validate(data) {
+        let violations: Vec<SchemaViolation> = errors
+            .map(|e| SchemaViolation {
+                path: e.instance_path.to_string(),
+                keyword: format!("{:?}", e.kind),
+                message: e.to_string(),
+            })
+            .collect();
+
+        let violations_json = serde_json::to_string(&violations)
+            .unwrap_or_else(|_| "[]".to_string());
+        return Err(format!("Schema validation failed: {}", violations_json));
+    }
+
+    Ok(())
+}
+
+fn app_data_dir() -> PathBuf {
+    match std::env::var("APPDATA") {
         Ok(appdata) => Path::new(&appdata).join("ucanduit"),
         Err(_) => {
             match std::env::var("HOME") {
@@ -14,54 +80,635 @@ async fn write_json_file(filename: String, data: JsonValue) -> Result<(), String
                 }
             }
         }
-    };
-    
+    }
+}
+
+// Every command that takes a bare filename joins it onto the app directory,
+// so anything that could climb out of it (path separators, `..`, or an
+// absolute path that would make `Path::join` discard the app directory
+// entirely) must be rejected up front.
+fn is_safe_filename(filename: &str) -> bool {
+    !filename.is_empty()
+        && filename != "."
+        && filename != ".."
+        && !filename.contains('/')
+        && !filename.contains('\\')
+}
+
+// Resolves `filename` to a path inside the app directory, rejecting anything
+// `is_safe_filename` flags. Every command that takes a bare filename should
+// go through this instead of joining onto `app_data_dir()` directly, so the
+// sandboxing check can't be forgotten on a new command.
+fn sandboxed_path(filename: &str) -> Result<PathBuf, String> {
+    if !is_safe_filename(filename) {
+        return Err(format!("Invalid filename: {}", filename));
+    }
+    Ok(app_data_dir().join(filename))
+}
+
+// Writes `data` to `file_path` without ever leaving a truncated file behind:
+// serialize to a sibling `.tmp` file, fsync it, roll the previous good copy
+// to `.bak`, then rename the `.tmp` into place. Rename is atomic within a
+// filesystem, so a crash can only ever lose the write in progress, not the
+// file that was already there.
+fn write_json_atomic(file_path: &Path, data: &JsonValue) -> Result<(), String> {
+    let json_string = serde_json::to_string_pretty(data)
+        .map_err(|e| format!("Failed to serialize JSON: {}", e))?;
+
+    let mut tmp_path = file_path.as_os_str().to_os_string();
+    tmp_path.push(".tmp");
+    let tmp_path = PathBuf::from(tmp_path);
+
+    {
+        let mut tmp_file = File::create(&tmp_path)
+            .map_err(|e| format!("Failed to create temp file: {}", e))?;
+        tmp_file.write_all(json_string.as_bytes())
+            .map_err(|e| format!("Failed to write temp file: {}", e))?;
+        tmp_file.sync_all()
+            .map_err(|e| format!("Failed to sync temp file: {}", e))?;
+    }
+
+    if file_path.exists() {
+        let mut bak_path = file_path.as_os_str().to_os_string();
+        bak_path.push(".bak");
+        let bak_path = PathBuf::from(bak_path);
+        // Copy rather than rename: the original must stay at `file_path`
+        // until the rename below atomically replaces it, so a crash between
+        // the backup and the rename still leaves a readable main file.
+        fs::copy(file_path, &bak_path)
+            .map_err(|e| format!("Failed to roll back up previous file: {}", e))?;
+    }
+
+    fs::rename(&tmp_path, file_path)
+        .map_err(|e| format!("Failed to finalize write: {}", e))
+}
+
+const SCHEMA_VERSION_FIELD: &str = "_schemaVersion";
+
+// A migration upgrades a document from the version immediately below
+// `to_version` to `to_version`. Add new entries here as the document shape
+// changes; `CURRENT_SCHEMA_VERSION` must match the highest `to_version`.
+type MigrationFn = fn(JsonValue) -> JsonValue;
+
+const CURRENT_SCHEMA_VERSION: u64 = 1;
+
+static MIGRATIONS: &[(u64, MigrationFn)] = &[
+    // (2, migrate_v1_to_v2),
+];
+
+fn schema_version_of(data: &JsonValue) -> u64 {
+    data.get(SCHEMA_VERSION_FIELD).and_then(JsonValue::as_u64).unwrap_or(1)
+}
+
+fn stamp_schema_version(data: &mut JsonValue, version: u64) {
+    if let Some(obj) = data.as_object_mut() {
+        obj.insert(SCHEMA_VERSION_FIELD.to_string(), JsonValue::from(version));
+    }
+}
+
+// Runs every pending migration against `data` in order and stamps the result
+// with the schema version it lands on.
+fn run_migrations(mut data: JsonValue) -> (JsonValue, u64) {
+    let mut version = schema_version_of(&data);
+
+    for (to_version, migrate) in MIGRATIONS {
+        if version < *to_version {
+            data = migrate(data);
+            version = *to_version;
+        }
+    }
+
+    stamp_schema_version(&mut data, version);
+    (data, version)
+}
+
+#[tauri::command]
+async fn write_json_file(filename: String, mut data: JsonValue, schema: Option<JsonValue>) -> Result<(), String> {
+    if data.get(SCHEMA_VERSION_FIELD).is_none() {
+        stamp_schema_version(&mut data, CURRENT_SCHEMA_VERSION);
+    }
+
+    if let Some(schema) = &schema {
+        validate_against_schema(&data, schema)?;
+    }
+
+    let app_dir = app_data_dir();
+
     if let Err(e) = fs::create_dir_all(&app_dir) {
         return Err(format!("Failed to create app directory: {}", e));
     }
-    
-    let file_path = app_dir.join(&filename);
-    
-    match serde_json::to_string_pretty(&data) {
-        Ok(json_string) => {
-            match fs::write(&file_path, json_string) {
-                Ok(_) => Ok(()),
-                Err(e) => Err(format!("Failed to write file: {}", e))
-            }
-        },
-        Err(e) => Err(format!("Failed to serialize JSON: {}", e))
-    }
+
+    let file_path = sandboxed_path(&filename)?;
+    write_json_atomic(&file_path, &data)
 }
 
 #[tauri::command]
-async fn read_json_file(filename: String) -> Result<JsonValue, String> {
-    let app_dir = match std::env::var("APPDATA") {
-        Ok(appdata) => Path::new(&appdata).join("ucanduit"),
-        Err(_) => {
-            match std::env::var("HOME") {
-                Ok(home) => Path::new(&home).join(".ucanduit"),
-                Err(_) => {
-                    std::env::current_dir().unwrap().join("data")
-                }
-            }
-        }
-    };
-    
-    let file_path = app_dir.join(&filename);
-    
+async fn read_json_file(filename: String, schema: Option<JsonValue>) -> Result<JsonValue, String> {
+    let file_path = sandboxed_path(&filename)?;
+
     if !file_path.exists() {
         return Err(format!("File does not exist: {}", filename));
     }
-    
-    match fs::read_to_string(&file_path) {
+
+    let json_data = match fs::read_to_string(&file_path) {
         Ok(contents) => {
             match serde_json::from_str::<JsonValue>(&contents) {
-                Ok(json_data) => Ok(json_data),
-                Err(e) => Err(format!("Failed to parse JSON: {}", e))
+                Ok(json_data) => json_data,
+                Err(e) => return Err(format!("Failed to parse JSON: {}", e))
             }
         },
-        Err(e) => Err(format!("Failed to read file: {}", e))
+        Err(e) => return Err(format!("Failed to read file: {}", e))
+    };
+
+    let original_version = schema_version_of(&json_data);
+    let (json_data, new_version) = run_migrations(json_data);
+    if new_version != original_version {
+        write_json_atomic(&file_path, &json_data)?;
+    }
+
+    if let Some(schema) = &schema {
+        validate_against_schema(&json_data, schema)?;
+    }
+
+    Ok(json_data)
+}
+
+// Re-validates a file already on disk against `schema`, without requiring
+// the caller to also hold a copy of the data in memory.
+#[tauri::command]
+async fn validate_json_file(filename: String, schema: JsonValue) -> Result<(), String> {
+    let file_path = sandboxed_path(&filename)?;
+
+    if !file_path.exists() {
+        return Err(format!("File does not exist: {}", filename));
+    }
+
+    let contents = fs::read_to_string(&file_path)
+        .map_err(|e| format!("Failed to read file: {}", e))?;
+    let json_data: JsonValue = serde_json::from_str(&contents)
+        .map_err(|e| format!("Failed to parse JSON: {}", e))?;
+
+    validate_against_schema(&json_data, &schema)
+}
+
+// Returns the schema version a file is currently stored at, without running
+// any migrations, so the frontend can flag documents that are out of date.
+#[tauri::command]
+async fn current_schema_version(filename: String) -> Result<u64, String> {
+    let file_path = sandboxed_path(&filename)?;
+
+    if !file_path.exists() {
+        return Err(format!("File does not exist: {}", filename));
+    }
+
+    let contents = fs::read_to_string(&file_path)
+        .map_err(|e| format!("Failed to read file: {}", e))?;
+    let json_data: JsonValue = serde_json::from_str(&contents)
+        .map_err(|e| format!("Failed to parse JSON: {}", e))?;
+
+    Ok(schema_version_of(&json_data))
+}
+
+// Forces a file through the migration pipeline and persists the result,
+// regardless of whether `read_json_file` has already been called on it.
+#[tauri::command]
+async fn migrate_json_file(filename: String) -> Result<JsonValue, String> {
+    let file_path = sandboxed_path(&filename)?;
+
+    if !file_path.exists() {
+        return Err(format!("File does not exist: {}", filename));
+    }
+
+    let contents = fs::read_to_string(&file_path)
+        .map_err(|e| format!("Failed to read file: {}", e))?;
+    let json_data: JsonValue = serde_json::from_str(&contents)
+        .map_err(|e| format!("Failed to parse JSON: {}", e))?;
+
+    let (json_data, _) = run_migrations(json_data);
+    write_json_atomic(&file_path, &json_data)?;
+
+    Ok(json_data)
+}
+
+#[derive(Serialize)]
+struct FileInfo {
+    name: String,
+    size: u64,
+    modified: u64,
+}
+
+#[tauri::command]
+fn list_json_files() -> Result<Vec<FileInfo>, String> {
+    let app_dir = app_data_dir();
+
+    if !app_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut files = Vec::new();
+
+    for entry in fs::read_dir(&app_dir).map_err(|e| format!("Failed to read app directory: {}", e))? {
+        let entry = entry.map_err(|e| format!("Failed to read directory entry: {}", e))?;
+        let metadata = entry.metadata().map_err(|e| format!("Failed to read file metadata: {}", e))?;
+
+        if !metadata.is_file() {
+            continue;
+        }
+
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if name.ends_with(".tmp") || name.ends_with(".bak") {
+            continue;
+        }
+
+        let modified = metadata
+            .modified()
+            .ok()
+            .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+            .map(|duration| duration.as_secs())
+            .unwrap_or(0);
+
+        files.push(FileInfo {
+            name,
+            size: metadata.len(),
+            modified,
+        });
+    }
+
+    Ok(files)
+}
+
+#[tauri::command]
+fn delete_json_file(filename: String) -> Result<(), String> {
+    let file_path = sandboxed_path(&filename)?;
+
+    if !file_path.exists() {
+        return Err(format!("File does not exist: {}", filename));
+    }
+
+    fs::remove_file(&file_path).map_err(|e| format!("Failed to delete file: {}", e))
+}
+
+#[tauri::command]
+fn rename_json_file(from: String, to: String) -> Result<(), String> {
+    let from_path = sandboxed_path(&from)?;
+    let to_path = sandboxed_path(&to)?;
+
+    if !from_path.exists() {
+        return Err(format!("File does not exist: {}", from));
+    }
+    if to_path.exists() {
+        return Err(format!("A file named {} already exists", to));
     }
+
+    fs::rename(&from_path, &to_path).map_err(|e| format!("Failed to rename file: {}", e))
+}
+
+fn http_client(connect_timeout_secs: Option<u64>, read_timeout_secs: Option<u64>) -> Result<reqwest::Client, String> {
+    reqwest::Client::builder()
+        .connect_timeout(Duration::from_secs(connect_timeout_secs.unwrap_or(DEFAULT_CONNECT_TIMEOUT_SECS)))
+        .timeout(Duration::from_secs(read_timeout_secs.unwrap_or(DEFAULT_READ_TIMEOUT_SECS)))
+        .build()
+        .map_err(|e| format!("Failed to build HTTP client: {}", e))
+}
+
+// Reads a local file and PUTs its contents to `endpoint` so it can be restored
+// later, even on a different machine. `method` defaults to PUT; pass "POST"
+// for endpoints that expect a create-style upload instead of a replace.
+#[tauri::command]
+async fn backup_json_file(
+    filename: String,
+    endpoint: String,
+    auth_token: Option<String>,
+    method: Option<String>,
+    connect_timeout_secs: Option<u64>,
+    read_timeout_secs: Option<u64>,
+) -> Result<(), String> {
+    let file_path = sandboxed_path(&filename)?;
+
+    let contents = fs::read_to_string(&file_path)
+        .map_err(|e| format!("Failed to read file: {}", e))?;
+
+    let client = http_client(connect_timeout_secs, read_timeout_secs)?;
+
+    let mut request = match method.as_deref() {
+        Some("POST") | Some("post") => client.post(&endpoint),
+        _ => client.put(&endpoint),
+    };
+    request = request.header("Content-Type", "application/json").body(contents);
+    if let Some(token) = auth_token {
+        request = request.bearer_auth(token);
+    }
+
+    let response = request.send().await.map_err(|e| format!("Backup request failed: {}", e))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("Backup failed with status {}: {}", status, body));
+    }
+
+    Ok(())
+}
+
+// Fetches the remote copy of a file from `endpoint` and writes it through the
+// same atomic write path used locally, so a failed restore can't corrupt the
+// file it was trying to replace.
+#[tauri::command]
+async fn restore_json_file(
+    filename: String,
+    endpoint: String,
+    auth_token: Option<String>,
+    connect_timeout_secs: Option<u64>,
+    read_timeout_secs: Option<u64>,
+) -> Result<(), String> {
+    let file_path = sandboxed_path(&filename)?;
+    let app_dir = app_data_dir();
+    fs::create_dir_all(&app_dir).map_err(|e| format!("Failed to create app directory: {}", e))?;
+
+    let client = http_client(connect_timeout_secs, read_timeout_secs)?;
+
+    let mut request = client.get(&endpoint);
+    if let Some(token) = auth_token {
+        request = request.bearer_auth(token);
+    }
+
+    let response = request.send().await.map_err(|e| format!("Restore request failed: {}", e))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("Restore failed with status {}: {}", status, body));
+    }
+
+    let body = response.text().await.map_err(|e| format!("Failed to read response body: {}", e))?;
+    let json_data: JsonValue = serde_json::from_str(&body)
+        .map_err(|e| format!("Restored data is not valid JSON: {}", e))?;
+
+    write_json_atomic(&file_path, &json_data)
+}
+
+fn asset_cache_dir() -> PathBuf {
+    app_data_dir().join("cache")
+}
+
+fn asset_cache_index_path() -> PathBuf {
+    asset_cache_dir().join("index.json")
+}
+
+// Guards the index's read-modify-write cycle so concurrent `cache_remote_asset`
+// calls (e.g. a frontend prefetching several images at once) can't race and
+// clobber each other's freshly-inserted entries.
+static CACHE_INDEX_LOCK: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
+
+// Maps source URL -> content digest, so a repeat `cache_remote_asset` call
+// for the same URL can confirm the asset is already cached without touching
+// the network, even though the cache itself is addressed by content hash.
+fn load_cache_index() -> HashMap<String, String> {
+    fs::read_to_string(asset_cache_index_path())
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+fn save_cache_index(index: &HashMap<String, String>) -> Result<(), String> {
+    let json_value = serde_json::to_value(index)
+        .map_err(|e| format!("Failed to serialize cache index: {}", e))?;
+    write_json_atomic(&asset_cache_index_path(), &json_value)
+}
+
+// Inserts `url` -> `digest` into the index under `CACHE_INDEX_LOCK`, so the
+// load/insert/save cycle is never interleaved with another caller's.
+fn record_cache_entry(url: String, digest: String) -> Result<(), String> {
+    let _guard = CACHE_INDEX_LOCK.lock().unwrap();
+    let mut index = load_cache_index();
+    index.insert(url, digest);
+    save_cache_index(&index)
+}
+
+// Bumps a cached asset's modified time on access so LRU eviction can tell
+// recently-used entries apart from stale ones, without relying on atime
+// (which is commonly disabled at the filesystem level).
+fn touch_cache_entry(path: &Path) {
+    if let Ok(file) = fs::OpenOptions::new().write(true).open(path) {
+        let _ = file.set_modified(SystemTime::now());
+    }
+}
+
+// Evicts least-recently-used cache entries until the directory is back under
+// `byte_budget`. `exempt` is never evicted, even if it's the oldest (or only)
+// entry — it's the asset `cache_remote_asset` is about to return, and handing
+// back a path it just deleted would be worse than letting the cache run over
+// budget by one entry.
+fn evict_cache_entries_over_budget(byte_budget: u64, exempt: &Path) -> Result<(), String> {
+    let dir = asset_cache_dir();
+    let mut entries: Vec<(PathBuf, u64, SystemTime)> = Vec::new();
+    let mut total_size: u64 = 0;
+
+    for entry in fs::read_dir(&dir).map_err(|e| format!("Failed to read cache directory: {}", e))? {
+        let entry = entry.map_err(|e| format!("Failed to read cache entry: {}", e))?;
+        let path = entry.path();
+
+        if path == asset_cache_index_path() {
+            continue;
+        }
+
+        let metadata = entry.metadata().map_err(|e| format!("Failed to read cache entry metadata: {}", e))?;
+        if !metadata.is_file() {
+            continue;
+        }
+
+        let last_used = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+        total_size += metadata.len();
+        entries.push((path, metadata.len(), last_used));
+    }
+
+    if total_size <= byte_budget {
+        return Ok(());
+    }
+
+    entries.sort_by_key(|(_, _, last_used)| *last_used);
+
+    for (path, size, _) in entries {
+        if total_size <= byte_budget {
+            break;
+        }
+        if path == exempt {
+            continue;
+        }
+        fs::remove_file(&path).map_err(|e| format!("Failed to evict cached asset: {}", e))?;
+        total_size = total_size.saturating_sub(size);
+    }
+
+    Ok(())
+}
+
+// Downloads `url` once and serves it from disk on every later call. Assets
+// are stored by the MD5 digest of their bytes, so two URLs that happen to
+// serve identical content share one cached file.
+#[tauri::command]
+async fn cache_remote_asset(
+    url: String,
+    max_cache_bytes: Option<u64>,
+    connect_timeout_secs: Option<u64>,
+    read_timeout_secs: Option<u64>,
+) -> Result<String, String> {
+    let cache_dir = asset_cache_dir();
+    fs::create_dir_all(&cache_dir).map_err(|e| format!("Failed to create cache directory: {}", e))?;
+
+    let index = load_cache_index();
+
+    if let Some(digest) = index.get(&url) {
+        let cached_path = cache_dir.join(digest);
+        if cached_path.exists() {
+            touch_cache_entry(&cached_path);
+            return Ok(cached_path.to_string_lossy().into_owned());
+        }
+    }
+
+    let client = http_client(connect_timeout_secs, read_timeout_secs)?;
+    let response = client.get(&url).send().await.map_err(|e| format!("Failed to fetch asset: {}", e))?;
+    if !response.status().is_success() {
+        return Err(format!("Failed to fetch asset: HTTP {}", response.status()));
+    }
+    let bytes = response.bytes().await.map_err(|e| format!("Failed to read asset body: {}", e))?;
+
+    let digest = format!("{:x}", md5::compute(&bytes));
+    let asset_path = cache_dir.join(&digest);
+
+    if !asset_path.exists() {
+        fs::write(&asset_path, &bytes).map_err(|e| format!("Failed to write cached asset: {}", e))?;
+    }
+
+    record_cache_entry(url, digest)?;
+
+    evict_cache_entries_over_budget(max_cache_bytes.unwrap_or(DEFAULT_CACHE_BYTE_BUDGET), &asset_path)?;
+
+    Ok(asset_path.to_string_lossy().into_owned())
+}
+
+#[tauri::command]
+fn clear_asset_cache() -> Result<(), String> {
+    let cache_dir = asset_cache_dir();
+    if cache_dir.exists() {
+        fs::remove_dir_all(&cache_dir).map_err(|e| format!("Failed to clear cache: {}", e))?;
+    }
+    Ok(())
+}
+
+const GITHUB_USER_AGENT: &str = "ucanduit-template-updater";
+
+#[derive(Serialize)]
+struct TemplateAsset {
+    name: String,
+    download_url: String,
+}
+
+#[derive(Serialize)]
+struct TemplateUpdateInfo {
+    update_available: bool,
+    latest_version: String,
+    release_notes: String,
+    assets: Vec<TemplateAsset>,
+}
+
+#[derive(serde::Deserialize)]
+struct GithubRelease {
+    tag_name: String,
+    body: Option<String>,
+    assets: Vec<GithubReleaseAsset>,
+}
+
+#[derive(serde::Deserialize)]
+struct GithubReleaseAsset {
+    name: String,
+    browser_download_url: String,
+}
+
+// Checks `repo`'s latest GitHub release against `current_version` using
+// semver ordering, mirroring a firmware-update flow for the bundled JSON
+// templates instead of the app binary itself.
+#[tauri::command]
+async fn check_template_update(
+    repo: String,
+    current_version: String,
+    connect_timeout_secs: Option<u64>,
+    read_timeout_secs: Option<u64>,
+) -> Result<TemplateUpdateInfo, String> {
+    let url = format!("https://api.github.com/repos/{}/releases/latest", repo);
+
+    let client = http_client(connect_timeout_secs, read_timeout_secs)?;
+
+    let response = client
+        .get(&url)
+        .header("User-Agent", GITHUB_USER_AGENT)
+        .header("Accept", "application/vnd.github+json")
+        .send()
+        .await
+        .map_err(|e| format!("Failed to query GitHub releases: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("GitHub releases request failed with status {}", response.status()));
+    }
+
+    let release: GithubRelease = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse GitHub release: {}", e))?;
+
+    let latest_version = release.tag_name.trim_start_matches('v').to_string();
+
+    let current = semver::Version::parse(current_version.trim_start_matches('v'))
+        .map_err(|e| format!("Invalid current version {}: {}", current_version, e))?;
+    let latest = semver::Version::parse(&latest_version)
+        .map_err(|e| format!("Invalid release version {}: {}", latest_version, e))?;
+
+    Ok(TemplateUpdateInfo {
+        update_available: latest > current,
+        latest_version,
+        release_notes: release.body.unwrap_or_default(),
+        assets: release
+            .assets
+            .into_iter()
+            .map(|asset| TemplateAsset {
+                name: asset.name,
+                download_url: asset.browser_download_url,
+            })
+            .collect(),
+    })
+}
+
+// Downloads a template asset surfaced by `check_template_update` and writes
+// it through the atomic write path, after confirming it actually parses as
+// JSON so a bad release can't brick the local file.
+#[tauri::command]
+async fn apply_template_update(
+    asset_url: String,
+    filename: String,
+    connect_timeout_secs: Option<u64>,
+    read_timeout_secs: Option<u64>,
+) -> Result<(), String> {
+    let file_path = sandboxed_path(&filename)?;
+
+    let client = http_client(connect_timeout_secs, read_timeout_secs)?;
+
+    let response = client
+        .get(&asset_url)
+        .header("User-Agent", GITHUB_USER_AGENT)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to download template: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("Failed to download template: HTTP {}", response.status()));
+    }
+
+    let bytes = response.bytes().await.map_err(|e| format!("Failed to read template body: {}", e))?;
+    let json_data: JsonValue = serde_json::from_slice(&bytes)
+        .map_err(|e| format!("Downloaded template is not valid JSON: {}", e))?;
+
+    fs::create_dir_all(app_data_dir()).map_err(|e| format!("Failed to create app directory: {}", e))?;
+
+    write_json_atomic(&file_path, &json_data)
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
@@ -71,7 +718,19 @@ pub fn run() {
     .plugin(tauri_plugin_http::init())
     .invoke_handler(tauri::generate_handler![
       write_json_file,
-      read_json_file
+      read_json_file,
+      validate_json_file,
+      current_schema_version,
+      migrate_json_file,
+      list_json_files,
+      delete_json_file,
+      rename_json_file,
+      backup_json_file,
+      restore_json_file,
+      cache_remote_asset,
+      clear_asset_cache,
+      check_template_update,
+      apply_template_update
     ])
     .setup(|app| {
       if cfg!(debug_assertions) {